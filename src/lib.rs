@@ -5,38 +5,123 @@
 )]
 
 use std::fmt;
-use std::collections::HashMap;
-use rand::Rng;
+use std::collections::{HashMap, HashSet};
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
 
 /// Represents a player in the tournament.
-#[derive(Debug, PartialEq, Clone, Hash, Eq)]
+///
+/// Equality and hashing are based on `id` alone: two `Player`
+/// values with the same id are considered the same player even if `rating`
+/// has since changed.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Player {
     pub id: u32,
     pub name: String,
+    /// Elo-style skill rating, used to weight match simulation.
+    pub rating: f64,
+}
+
+/// The default Elo rating assigned to a player who isn't explicitly rated.
+pub const DEFAULT_RATING: f64 = 1500.0;
+
+impl Player {
+    /// Creates a new player with the default rating.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The player's unique identifier.
+    /// * `name` - The player's display name.
+    ///
+    /// # Returns
+    ///
+    /// A new `Player` instance rated at [`DEFAULT_RATING`].
+    #[must_use]
+    pub const fn new(id: u32, name: String) -> Self {
+        Self {
+            id,
+            name,
+            rating: DEFAULT_RATING,
+        }
+    }
+
+    /// Creates a new player with an explicit rating.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The player's unique identifier.
+    /// * `name` - The player's display name.
+    /// * `rating` - The player's Elo rating.
+    ///
+    /// # Returns
+    ///
+    /// A new `Player` instance rated at `rating`.
+    #[must_use]
+    pub const fn with_rating(id: u32, name: String, rating: f64) -> Self {
+        Self { id, name, rating }
+    }
+}
+
+impl PartialEq for Player {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Eq for Player {}
+
+impl std::hash::Hash for Player {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+    }
 }
 
 /// Represents a match between two players.
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Match {
     pub player1: Player,
     pub player2: Player,
     pub winner: Option<Player>,
+    /// `true` if this entry records an automatic bye (no match was
+    /// actually played) rather than a real result.
+    pub bye: bool,
 }
 
 /// Enum to represent the type of tournament.
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TournamentType {
     SingleElimination,
     DoubleElimination,
     Swiss,
+    RoundRobin,
 }
 
 /// Represents a tournament.
-#[derive(Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Tournament {
     pub tournament_type: TournamentType,
     pub players: Vec<Player>,
     pub matches: Vec<Match>,
+    /// RNG used for every simulated match, so a given seed always produces
+    /// the same bracket. Seeded via [`Tournament::with_seed`]; otherwise
+    /// drawn from entropy. Not part of the serialized state: a
+    /// deserialized tournament always gets a fresh one.
+    #[cfg_attr(feature = "serde", serde(skip, default = "Tournament::random_rng"))]
+    rng: StdRng,
+}
+
+impl fmt::Debug for Tournament {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Tournament")
+            .field("tournament_type", &self.tournament_type)
+            .field("players", &self.players)
+            .field("matches", &self.matches)
+            .finish_non_exhaustive()
+    }
 }
 
 /// Helper struct to hold round results.
@@ -53,6 +138,9 @@ impl fmt::Display for Player {
 
 impl fmt::Display for Match {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.bye {
+            return write!(f, "{} - Bye, advances automatically", self.player1);
+        }
         match &self.winner {
             Some(winner) => write!(
                 f,
@@ -80,7 +168,131 @@ impl Tournament {
             tournament_type,
             players,
             matches: Vec::new(),
+            rng: Self::random_rng(),
+        }
+    }
+
+    /// Builds a fresh, entropy-seeded RNG.
+    ///
+    /// Used both by [`Tournament::new`] and, when the `serde` feature is
+    /// enabled, to give a deserialized tournament an RNG of its own.
+    fn random_rng() -> StdRng {
+        StdRng::from_entropy()
+    }
+
+    /// Creates a new tournament whose match simulation is driven by a
+    /// seeded RNG, so the same seed and player list always produce the
+    /// identical sequence of matches.
+    ///
+    /// # Arguments
+    ///
+    /// * `tournament_type` - The type of the tournament.
+    /// * `players` - A vector of players participating in the tournament.
+    /// * `seed` - The seed used to initialize the tournament's RNG.
+    ///
+    /// # Returns
+    ///
+    /// A new `Tournament` instance with deterministic match simulation.
+    #[must_use]
+    pub fn with_seed(tournament_type: TournamentType, players: Vec<Player>, seed: u64) -> Self {
+        Self {
+            tournament_type,
+            players,
+            matches: Vec::new(),
+            rng: StdRng::seed_from_u64(seed),
+        }
+    }
+
+    /// Serializes the tournament's type, players, and match history to
+    /// JSON, so a bracket (in progress or complete) can be persisted and
+    /// reloaded later for rendering or auditing.
+    ///
+    /// The RNG is not part of the serialized state; see
+    /// [`Tournament::from_json`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the tournament cannot be serialized, which should not
+    /// happen for a well-formed `Tournament`.
+    #[cfg(feature = "serde")]
+    #[must_use]
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("tournament should always serialize")
+    }
+
+    /// Deserializes a tournament previously produced by
+    /// [`Tournament::to_json`].
+    ///
+    /// The restored tournament gets a fresh, entropy-seeded RNG, since the
+    /// original one isn't serialized; calling `start()` on it will not
+    /// reproduce the original bracket.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `json` is not valid JSON or doesn't match the
+    /// expected `Tournament` shape.
+    #[cfg(feature = "serde")]
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+
+    /// Randomly shuffles the player list using the tournament's RNG, so
+    /// the initial bracket draw isn't entirely caller-controlled.
+    ///
+    /// Call this before [`Tournament::start`]; calling it afterwards still
+    /// reorders `players` but leaves the recorded matches unchanged.
+    pub fn shuffle_seeding(&mut self) {
+        self.players.shuffle(&mut self.rng);
+    }
+
+    /// Reorders the player list into standard tournament bracket seeding,
+    /// so the strongest seeds can only meet in later rounds once the list
+    /// is fed through [`Tournament::play_round`]'s sequential pairing.
+    ///
+    /// This is the recursive "1 vs 8, 4 vs 5, 2 vs 7, 3 vs 6" construction
+    /// (for an 8-seed bracket), not a plain "1 vs n, 2 vs n-1" ordering:
+    /// the latter looks right on paper but actually lets seed 1 and seed 2
+    /// meet as early as the semifinal once consumed by adjacent-pair
+    /// chunking. For a non-power-of-two field, the seeds that would face a
+    /// phantom opponent beyond the bracket's bottom are moved to the front
+    /// so they line up with the byes that [`Tournament::play_round_with_byes`]
+    /// hands to the top of the list. Those bye seeds then open the second
+    /// round side by side and are paired with each other there, so in a
+    /// non-power-of-two field the top seeds are not kept apart (with six
+    /// players, seeds 1 and 2 meet in the semifinal).
+    ///
+    /// Assumes `self.players` is already ordered best-seed-first.
+    pub fn seed_standard(&mut self) {
+        let n = self.players.len();
+        if n < 2 {
+            return;
+        }
+        let bracket_size = n.next_power_of_two();
+        let mut seeds = vec![1_usize];
+        while seeds.len() < bracket_size {
+            let next_size = seeds.len() * 2;
+            seeds = seeds
+                .into_iter()
+                .flat_map(|seed| [seed, next_size + 1 - seed])
+                .collect();
         }
+
+        let mut byes = Vec::new();
+        let mut playing = Vec::new();
+        for pair in seeds.chunks_exact(2) {
+            match (pair[0] <= n, pair[1] <= n) {
+                (true, true) => playing.extend_from_slice(pair),
+                (true, false) => byes.push(pair[0]),
+                (false, true) => byes.push(pair[1]),
+                (false, false) => {}
+            }
+        }
+        byes.extend(playing);
+
+        self.players = byes
+            .into_iter()
+            .map(|seed| self.players[seed - 1].clone())
+            .collect();
     }
 
     /// Starts the tournament.
@@ -93,35 +305,69 @@ impl Tournament {
             TournamentType::SingleElimination => self.start_single_elimination(),
             TournamentType::DoubleElimination => self.start_double_elimination(),
             TournamentType::Swiss => self.start_swiss(),
+            TournamentType::RoundRobin => self.start_round_robin(),
         }
     }
 
+    /// Starts a round-robin tournament, where every player plays every
+    /// other player exactly once.
+    ///
+    /// # Returns
+    ///
+    /// The top-ranked player according to [`Tournament::standings`], if any.
+    fn start_round_robin(&mut self) -> Option<Player> {
+        let players = self.players.clone();
+        for (i, player1) in players.iter().enumerate() {
+            for player2 in &players[i + 1..] {
+                self.simulate_match(player1, player2);
+            }
+        }
+        self.standings().into_iter().map(|(player, _)| player).next()
+    }
+
+    /// Computes the full standings across every match played so far,
+    /// ranked by number of wins (descending).
+    ///
+    /// Works for any tournament format, since it simply tallies wins
+    /// recorded in `self.matches`.
+    ///
+    /// # Returns
+    ///
+    /// A vector of `(Player, wins)` pairs, sorted from most to fewest wins;
+    /// players tied on wins are ordered by ascending id so the result is
+    /// deterministic regardless of hashmap iteration order.
+    #[must_use]
+    pub fn standings(&self) -> Vec<(Player, u32)> {
+        let mut wins: HashMap<Player, u32> = self
+            .players
+            .iter()
+            .map(|player| (player.clone(), 0))
+            .collect();
+        for match_ in &self.matches {
+            if let Some(winner) = &match_.winner {
+                *wins.entry(winner.clone()).or_insert(0) += 1;
+            }
+        }
+        let mut standings: Vec<_> = wins.into_iter().collect();
+        standings.sort_by(|(player_a, wins_a), (player_b, wins_b)| {
+            wins_b.cmp(wins_a).then_with(|| player_a.id.cmp(&player_b.id))
+        });
+        standings
+    }
+
     /// Starts a single elimination tournament.
     ///
     /// # Returns
     ///
     /// The winner of the tournament, if any.
     fn start_single_elimination(&mut self) -> Option<Player> {
-        let mut rng = rand::thread_rng(); // RNG for random number generation
         let mut round_players = self.players.clone();
+        if round_players.len() > 1 {
+            let byes = round_players.len().next_power_of_two() - round_players.len();
+            round_players = self.play_round_with_byes(&round_players, byes).winners;
+        }
         while round_players.len() > 1 {
-            let mut next_round_players = Vec::new();
-            for chunk in round_players.chunks(2) {
-                if chunk.len() == 2 {
-                    let winner_index = rng.gen_range(0..2); // Randomly select 0 or 1
-                    let winner = chunk[winner_index].clone(); // Select winner based on random index
-                    let match_ = Match {
-                        player1: chunk[0].clone(),
-                        player2: chunk[1].clone(),
-                        winner: Some(winner.clone()),
-                    };
-                    self.matches.push(match_);
-                    next_round_players.push(winner);
-                } else {
-                    next_round_players.push(chunk[0].clone());
-                }
-            }
-            round_players = next_round_players;
+            round_players = self.play_round(&round_players).winners;
         }
         round_players.first().cloned()
     }
@@ -135,11 +381,24 @@ impl Tournament {
         let mut winners_bracket = self.players.clone();
         let mut losers_bracket = Vec::new();
         let mut final_winner = None;
+        let mut first_round = true;
         while winners_bracket.len() > 1 || losers_bracket.len() > 1 {
-            let winners_round_result = self.play_round(&winners_bracket);
-            winners_bracket = winners_round_result.winners;
-            losers_bracket.extend(winners_round_result.losers);
-            if !losers_bracket.is_empty() {
+            // A bracket already down to its single finalist has nothing
+            // left to play; re-entering it into `play_round` would count
+            // as a fresh (phantom) bye win every time this loop spins
+            // waiting on the other bracket.
+            if winners_bracket.len() > 1 {
+                let winners_round_result = if first_round {
+                    let byes = winners_bracket.len().next_power_of_two() - winners_bracket.len();
+                    self.play_round_with_byes(&winners_bracket, byes)
+                } else {
+                    self.play_round(&winners_bracket)
+                };
+                winners_bracket = winners_round_result.winners;
+                losers_bracket.extend(winners_round_result.losers);
+            }
+            first_round = false;
+            if losers_bracket.len() > 1 {
                 let losers_round_result = self.play_round(&losers_bracket);
                 losers_bracket = losers_round_result.winners;
                 // Losers of losers bracket are eliminated, not added back
@@ -171,18 +430,54 @@ impl Tournament {
             .iter()
             .map(|p| (p.clone(), 0))
             .collect::<HashMap<Player, i32>>();
+        let mut played: HashSet<(u32, u32)> = HashSet::new();
+        let mut had_bye: HashSet<u32> = HashSet::new();
+        let mut opponents: HashMap<u32, Vec<u32>> = HashMap::new();
         for round in 0..rounds {
             println!("Round {}:", round + 1);
-            let round_matches = Self::pair_players_swiss(&scores);
+            let (round_matches, bye) = Self::pair_players_swiss(&scores, &played, &had_bye);
+            if let Some(bye_player) = bye {
+                had_bye.insert(bye_player.id);
+                self.matches.push(Match {
+                    player1: bye_player.clone(),
+                    player2: bye_player.clone(),
+                    winner: Some(bye_player.clone()),
+                    bye: true,
+                });
+                *scores.entry(bye_player).or_insert(0) += 1;
+            }
             for (player1, player2) in round_matches {
+                played.insert(Self::pair_key(player1.id, player2.id));
+                opponents.entry(player1.id).or_default().push(player2.id);
+                opponents.entry(player2.id).or_default().push(player1.id);
                 let winner = self.simulate_match(&player1, &player2);
                 *scores.entry(winner).or_insert(0) += 1;
             }
             Self::print_leaderboard(&scores);
         }
+
+        // Break ties with the Buchholz score: the sum of each player's
+        // opponents' final scores.
+        let final_scores: HashMap<u32, i32> = scores.iter().map(|(p, &s)| (p.id, s)).collect();
+        let buchholz = |id: u32| -> i32 {
+            opponents.get(&id).map_or(0, |opponent_ids| {
+                opponent_ids
+                    .iter()
+                    .filter_map(|opponent_id| final_scores.get(opponent_id))
+                    .sum()
+            })
+        };
         scores
             .into_iter()
-            .max_by_key(|&(_, score)| score)
+            .max_by(|(player_a, score_a), (player_b, score_b)| {
+                score_a
+                    .cmp(score_b)
+                    .then_with(|| buchholz(player_a.id).cmp(&buchholz(player_b.id)))
+                    // Final tiebreak so the winner doesn't depend on the
+                    // HashMap's per-process randomized iteration order when
+                    // two players are tied on both score and Buchholz.
+                    .then_with(|| player_b.id.cmp(&player_a.id))
+            })
             .map(|(player, _)| player)
     }
 
@@ -196,12 +491,12 @@ impl Tournament {
     ///
     /// A `RoundResult` containing the winners and losers of the round.
     fn play_round(&mut self, players: &[Player]) -> RoundResult {
-        let mut rng = rand::thread_rng();
         let mut winners = Vec::new();
         let mut losers = Vec::new();
         for chunk in players.chunks(2) {
             if chunk.len() == 2 {
-                let winner_index = rng.gen_range(0..2);
+                let p = Self::elo_expected_score(&chunk[0], &chunk[1]);
+                let winner_index = usize::from(self.rng.gen_range(0.0..1.0) >= p);
                 let winner = chunk[winner_index].clone();
                 let loser = chunk[1 - winner_index].clone();
                 winners.push(winner.clone());
@@ -211,15 +506,55 @@ impl Tournament {
                     player1: chunk[0].clone(),
                     player2: chunk[1].clone(),
                     winner: Some(winner.clone()),
+                    bye: false,
                 });
             } else {
-                // Odd player out automatically advances
-                winners.push(chunk[0].clone());
+                // Odd player out gets a bye and automatically advances
+                let player = chunk[0].clone();
+                self.matches.push(Match {
+                    player1: player.clone(),
+                    player2: player.clone(),
+                    winner: Some(player.clone()),
+                    bye: true,
+                });
+                winners.push(player);
             }
         }
         RoundResult { winners, losers }
     }
 
+    /// Plays a round of matches after giving the top `byes` seeds (ranked
+    /// best-first in `players`) an automatic bye.
+    ///
+    /// Used for a bracket's opening round, where the field may not be a
+    /// power of two; `byes` should be `players.len().next_power_of_two() - players.len()`
+    /// so no player receives more than one bye.
+    ///
+    /// # Arguments
+    ///
+    /// * `players` - The players entering this round, ranked best seed first.
+    /// * `byes` - How many of the top seeds sit this round out.
+    ///
+    /// # Returns
+    ///
+    /// A `RoundResult` containing the winners and losers of the round.
+    fn play_round_with_byes(&mut self, players: &[Player], byes: usize) -> RoundResult {
+        let (bye_players, playing_players) = players.split_at(byes.min(players.len()));
+        let mut result = self.play_round(playing_players);
+        for player in bye_players {
+            self.matches.push(Match {
+                player1: player.clone(),
+                player2: player.clone(),
+                winner: Some(player.clone()),
+                bye: true,
+            });
+        }
+        let mut winners = bye_players.to_vec();
+        winners.extend(result.winners);
+        result.winners = winners;
+        result
+    }
+
     /// Simulates a match between two players.
     ///
     /// # Arguments
@@ -231,18 +566,34 @@ impl Tournament {
     ///
     /// The winner of the match.
     fn simulate_match(&mut self, player1: &Player, player2: &Player) -> Player {
-        let mut rng = rand::thread_rng();
-        let winner_index = rng.gen_range(0..2);
-        let winner = if winner_index == 0 { player1 } else { player2 };
+        let p = Self::elo_expected_score(player1, player2);
+        let winner = if self.rng.gen_range(0.0..1.0) < p { player1 } else { player2 };
         // Record the match
         self.matches.push(Match {
             player1: player1.clone(),
             player2: player2.clone(),
             winner: Some(winner.clone()),
+            bye: false,
         });
         winner.clone()
     }
 
+    /// Computes player `a`'s expected probability of beating player `b`,
+    /// using the standard Elo expected-score formula based on their
+    /// ratings.
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - The first player.
+    /// * `b` - The second player.
+    ///
+    /// # Returns
+    ///
+    /// The probability, in `[0, 1]`, that `a` beats `b`.
+    fn elo_expected_score(a: &Player, b: &Player) -> f64 {
+        1.0 / (1.0 + 10f64.powf((b.rating - a.rating) / 400.0))
+    }
+
     /// Records a match with a specified winner.
     ///
     /// # Arguments
@@ -256,31 +607,70 @@ impl Tournament {
             player1: player1.clone(),
             player2: player2.clone(),
             winner: Some(winner.clone()),
+            bye: false,
         });
     }
 
-    /// Pairs players for a Swiss-system round based on their scores.
+    /// Pairs players for a Swiss-system round based on their scores,
+    /// avoiding rematches where possible.
+    ///
+    /// Players are considered in score order (highest first). Each player
+    /// is greedily matched with the highest-scoring remaining opponent
+    /// they have not yet faced, falling back to the closest-scoring
+    /// available opponent if everyone in their score group has already
+    /// been played. An odd player out is awarded a bye, preferring a
+    /// player who has not already had one.
     ///
     /// # Arguments
     ///
     /// * `scores` - A hashmap of players and their scores.
+    /// * `played_pairs` - Every pairing (by id, smaller id first) played so far.
+    /// * `had_bye` - Every player id that has already received a bye.
     ///
     /// # Returns
     ///
-    /// A vector of player pairs for the round.
-    fn pair_players_swiss(scores: &HashMap<Player, i32>) -> Vec<(Player, Player)> {
-        let mut players_sorted: Vec<_> = scores.iter().collect();
-        players_sorted.sort_by_key(|&(_, &score)| -score);
-        players_sorted
-            .chunks(2)
-            .filter_map(|chunk| {
-                if chunk.len() == 2 {
-                    Some((chunk[0].0.clone(), chunk[1].0.clone()))
-                } else {
-                    None
-                }
-            })
-            .collect()
+    /// A vector of player pairs for the round, plus the player (if any)
+    /// who receives this round's bye.
+    fn pair_players_swiss(
+        scores: &HashMap<Player, i32>,
+        played_pairs: &HashSet<(u32, u32)>,
+        had_bye: &HashSet<u32>,
+    ) -> (Vec<(Player, Player)>, Option<Player>) {
+        let mut remaining: Vec<Player> = scores.keys().cloned().collect();
+        remaining.sort_by(|a, b| scores[b].cmp(&scores[a]).then(a.id.cmp(&b.id)));
+
+        let bye = if remaining.len() % 2 == 1 {
+            let bye_index = remaining
+                .iter()
+                .rposition(|player| !had_bye.contains(&player.id))
+                .unwrap_or(remaining.len() - 1);
+            Some(remaining.remove(bye_index))
+        } else {
+            None
+        };
+
+        let mut pairs = Vec::new();
+        while !remaining.is_empty() {
+            let player = remaining.remove(0);
+            let opponent_index = remaining
+                .iter()
+                .position(|candidate| !Self::already_played(played_pairs, player.id, candidate.id))
+                .unwrap_or(0);
+            let opponent = remaining.remove(opponent_index);
+            pairs.push((player, opponent));
+        }
+        (pairs, bye)
+    }
+
+    /// Normalizes a pair of player ids so the smaller id comes first,
+    /// giving a canonical key for the `played` set.
+    const fn pair_key(a: u32, b: u32) -> (u32, u32) {
+        if a < b { (a, b) } else { (b, a) }
+    }
+
+    /// Checks whether two players have already faced each other.
+    fn already_played(played_pairs: &HashSet<(u32, u32)>, a: u32, b: u32) -> bool {
+        played_pairs.contains(&Self::pair_key(a, b))
     }
 
     /// Prints the leaderboard of the tournament.
@@ -314,13 +704,128 @@ mod tests {
     /// A vector of `Player` instances.
     fn create_players(num: u32) -> Vec<Player> {
         (1..=num)
-            .map(|i| Player {
-                id: i,
-                name: format!("Player {i}"),
-            })
+            .map(|i| Player::new(i, format!("Player {i}")))
             .collect()
     }
 
+    #[test]
+    fn test_elo_expected_score() {
+        let equal_a = Player::with_rating(1, "A".to_string(), 1500.0);
+        let equal_b = Player::with_rating(2, "B".to_string(), 1500.0);
+        assert!((Tournament::elo_expected_score(&equal_a, &equal_b) - 0.5).abs() < f64::EPSILON);
+
+        let favorite = Player::with_rating(3, "Favorite".to_string(), 1800.0);
+        let underdog = Player::with_rating(4, "Underdog".to_string(), 1400.0);
+        let p_favorite = Tournament::elo_expected_score(&favorite, &underdog);
+        let p_underdog = Tournament::elo_expected_score(&underdog, &favorite);
+        assert!(p_favorite > 0.5);
+        assert!(p_underdog < 0.5);
+        assert!((p_favorite + p_underdog - 1.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_round_robin_standings() {
+        let players = create_players(5);
+        let mut tournament = Tournament::with_seed(TournamentType::RoundRobin, players.clone(), 7);
+        let winner = tournament.start();
+        assert!(winner.is_some());
+
+        let expected_matches = players.len() * (players.len() - 1) / 2;
+        assert_eq!(tournament.matches.len(), expected_matches);
+
+        let standings = tournament.standings();
+        assert_eq!(standings.len(), players.len());
+        let total_wins: usize = standings.iter().map(|&(_, wins)| wins as usize).sum();
+        assert_eq!(total_wins, expected_matches);
+
+        // Same seed, same players: standings (including tie-break order)
+        // must come out identically on every run.
+        let mut tournament2 = Tournament::with_seed(TournamentType::RoundRobin, players, 7);
+        tournament2.start();
+        assert_eq!(standings, tournament2.standings());
+    }
+
+    #[test]
+    fn test_seeded_tournament_is_deterministic() {
+        let players = create_players(6);
+        let mut t1 = Tournament::with_seed(TournamentType::SingleElimination, players.clone(), 42);
+        let winner1 = t1.start();
+
+        let mut t2 = Tournament::with_seed(TournamentType::SingleElimination, players, 42);
+        let winner2 = t2.start();
+
+        assert_eq!(winner1, winner2);
+        assert_eq!(t1.matches, t2.matches);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_json_roundtrip() {
+        let players = create_players(4);
+        let mut tournament = Tournament::new(TournamentType::SingleElimination, players);
+        tournament.start();
+
+        let json = tournament.to_json();
+        let restored = Tournament::from_json(&json).expect("valid JSON should deserialize");
+
+        assert_eq!(restored.tournament_type, tournament.tournament_type);
+        assert_eq!(restored.players, tournament.players);
+        assert_eq!(restored.matches, tournament.matches);
+    }
+
+    #[test]
+    fn test_seed_standard_pairs_best_against_worst() {
+        let players = create_players(8);
+        let mut tournament = Tournament::new(TournamentType::SingleElimination, players.clone());
+        tournament.seed_standard();
+
+        // Standard recursive bracket seeding for 8 slots: 1v8, 4v5, 2v7, 3v6.
+        let expected_seeds = [1, 8, 4, 5, 2, 7, 3, 6];
+        let expected: Vec<_> = expected_seeds
+            .iter()
+            .map(|&seed| players[seed - 1].clone())
+            .collect();
+        assert_eq!(tournament.players, expected);
+    }
+
+    #[test]
+    fn test_seed_standard_keeps_top_two_seeds_apart_until_the_final() {
+        let mut players = create_players(8);
+        // Make seeds 1 and 2 overwhelmingly likely to win every match, so
+        // the only question is *when* they meet, not *whether* they win.
+        players[0].rating = 200_000.0;
+        players[1].rating = 200_000.0;
+        let mut tournament = Tournament::with_seed(TournamentType::SingleElimination, players, 4);
+        tournament.seed_standard();
+        tournament.start();
+
+        let top_two = |match_: &Match| {
+            let ids = [match_.player1.id, match_.player2.id];
+            ids.contains(&1) && ids.contains(&2)
+        };
+        let (before_final, final_match) = tournament
+            .matches
+            .split_at(tournament.matches.len() - 1);
+        assert!(
+            !before_final.iter().any(top_two),
+            "seeds 1 and 2 met before the final"
+        );
+        assert!(top_two(&final_match[0]), "seeds 1 and 2 didn't meet in the final");
+    }
+
+    #[test]
+    fn test_shuffle_seeding_is_a_permutation() {
+        let players = create_players(10);
+        let mut tournament =
+            Tournament::with_seed(TournamentType::SingleElimination, players.clone(), 99);
+        tournament.shuffle_seeding();
+
+        assert_eq!(tournament.players.len(), players.len());
+        for player in &players {
+            assert!(tournament.players.contains(player));
+        }
+    }
+
     #[test]
     fn test_single_elimination() {
         let players = create_players(8);
@@ -383,6 +888,44 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_single_elimination_non_power_of_two_field() {
+        let players = create_players(6);
+        let expected_byes = players.len().next_power_of_two() - players.len();
+        let mut tournament = Tournament::with_seed(TournamentType::SingleElimination, players, 1);
+        let winner = tournament.start();
+        assert!(winner.is_some());
+        for match_ in &tournament.matches {
+            assert!(match_.winner.is_some());
+        }
+        let byes = tournament.matches.iter().filter(|m| m.bye).count();
+        assert_eq!(byes, expected_byes);
+    }
+
+    #[test]
+    fn test_double_elimination_non_power_of_two_field() {
+        let players = create_players(6);
+        let mut tournament = Tournament::with_seed(TournamentType::DoubleElimination, players, 3);
+        let winner = tournament.start();
+        assert!(winner.is_some());
+        for match_ in &tournament.matches {
+            assert!(match_.winner.is_some());
+        }
+        // A finalist whose bracket has already collapsed to one player
+        // must not keep collecting a fresh bye win every time the loop
+        // spins waiting on the other bracket.
+        let mut bye_counts: HashMap<u32, u32> = HashMap::new();
+        for match_ in &tournament.matches {
+            if match_.bye {
+                *bye_counts.entry(match_.player1.id).or_insert(0) += 1;
+            }
+        }
+        assert!(
+            bye_counts.values().all(|&count| count <= 1),
+            "a player received more than one bye: {bye_counts:?}"
+        );
+    }
+
     #[test]
     fn test_swiss() {
         let players = create_players(8);
@@ -412,4 +955,51 @@ mod tests {
         }
         println!("Players' scores: {scores:?}");
     }
+
+    #[test]
+    fn test_swiss_avoids_rematches_and_tracks_byes() {
+        // An odd player count forces a bye every round.
+        let players = create_players(5);
+        let mut tournament = Tournament::with_seed(TournamentType::Swiss, players, 11);
+        let winner = tournament.start();
+        assert!(winner.is_some());
+
+        let mut seen_pairs = HashSet::new();
+        for match_ in &tournament.matches {
+            if match_.bye {
+                continue;
+            }
+            let key = Tournament::pair_key(match_.player1.id, match_.player2.id);
+            assert!(
+                seen_pairs.insert(key),
+                "players {} and {} were paired more than once",
+                match_.player1.id,
+                match_.player2.id
+            );
+        }
+
+        // Every match (including byes) awards exactly one win, so
+        // standings() must agree with the match log.
+        let total_wins: usize = tournament
+            .standings()
+            .iter()
+            .map(|&(_, wins)| wins as usize)
+            .sum();
+        assert_eq!(total_wins, tournament.matches.len());
+    }
+
+    #[test]
+    fn test_swiss_winner_is_deterministic_on_score_and_buchholz_tie() {
+        // This seed is known to leave multiple players tied on both Swiss
+        // score and Buchholz, which used to make `start_swiss`'s winner
+        // depend on HashMap's per-process randomized iteration order.
+        let players = create_players(6);
+        let mut t1 = Tournament::with_seed(TournamentType::Swiss, players.clone(), 8);
+        let winner1 = t1.start();
+
+        let mut t2 = Tournament::with_seed(TournamentType::Swiss, players, 8);
+        let winner2 = t2.start();
+
+        assert_eq!(winner1, winner2);
+    }
 }
\ No newline at end of file